@@ -0,0 +1,96 @@
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use slug::slugify;
+use tera::Context;
+
+use crate::{
+    meta::{extract_description_from_markdown, Meta},
+    Render,
+};
+
+use super::Entity;
+
+/// The article entity config.
+/// It parsed from season's `zine.toml` `[[article]]` entries.
+#[derive(Serialize, Deserialize)]
+pub struct Article {
+    pub slug: Option<String>,
+    pub title: String,
+    pub cover: Option<String>,
+    pub pub_date: NaiveDate,
+    pub author: Option<String>,
+    /// The markdown file name, relative to the season's directory.
+    pub file: String,
+    /// Topical tags for this article, declared alongside the `[[article]]` entry.
+    /// Aggregated crate-wide into the taxonomy listing pages.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(skip)]
+    pub markdown: String,
+    /// Article length from the season's jieba pass (CJK characters + latin words).
+    /// Only skipped on deserialize — `zine.toml` never sets this — since the
+    /// article template reads it back out of the `article` context key.
+    #[serde(skip_deserializing)]
+    pub word_count: u32,
+    /// Estimated reading time in minutes, derived from `word_count` and the
+    /// season's configured reading speeds. Serialized for the same reason as
+    /// `word_count`.
+    #[serde(skip_deserializing)]
+    pub reading_time: u32,
+}
+
+impl std::fmt::Debug for Article {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Article")
+            .field("slug", &self.slug)
+            .field("title", &self.title)
+            .field("cover", &self.cover)
+            .field("pub_date", &self.pub_date)
+            .field("author", &self.author)
+            .field("file", &self.file)
+            .field("tags", &self.tags)
+            .field("word_count", &self.word_count)
+            .field("reading_time", &self.reading_time)
+            .finish()
+    }
+}
+
+impl Article {
+    /// The article's slug. `parse` fills in a slugified title when none is set;
+    /// before that, the raw title is returned.
+    pub fn slug(&self) -> &str {
+        self.slug.as_deref().unwrap_or(&self.title)
+    }
+
+    fn description(&self) -> String {
+        extract_description_from_markdown(&self.markdown)
+    }
+}
+
+impl Entity for Article {
+    fn parse(&mut self, source: &Path) -> Result<()> {
+        self.markdown = fs::read_to_string(source.join(&self.file))?;
+        if self.slug.is_none() {
+            self.slug = Some(slugify(&self.title));
+        }
+        Ok(())
+    }
+
+    fn render(&self, mut context: Context, dest: &Path) -> Result<()> {
+        context.insert("article", &self);
+        context.insert(
+            "meta",
+            &Meta {
+                title: std::borrow::Cow::Borrowed(&self.title),
+                description: std::borrow::Cow::Owned(self.description()),
+                url: self.slug.as_deref().map(std::borrow::Cow::Borrowed),
+                image: self.cover.as_deref().map(std::borrow::Cow::Borrowed),
+            },
+        );
+        Render::render("article.jinja", &context, dest.to_path_buf())?;
+        Ok(())
+    }
+}
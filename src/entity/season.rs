@@ -1,4 +1,9 @@
-use std::{borrow::Cow, collections::BTreeMap, fs, path::Path};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use anyhow::Result;
 use jieba_rs::Jieba;
@@ -6,12 +11,47 @@ use serde::{Deserialize, Serialize};
 use tera::Context;
 
 use crate::{
+    feed::{build_feed, FeedMeta},
+    gemtext,
+    images::ImagePipeline,
     meta::{extract_description_from_markdown, Meta},
+    search::{SearchIndex, SearchIndexBuilder},
     Render,
 };
 
 use super::{article::Article, Entity};
 
+/// The bundled search runtime, written out next to each season's `search-index.json`.
+static SEARCH_RUNTIME_JS: &str = include_str!("../../static/search.js");
+
+fn default_feed_item_count() -> usize {
+    20
+}
+
+/// Default CJK reading speed, in characters per minute. Jieba segments CJK
+/// into words, but readers are conventionally timed by character count.
+fn default_cjk_reading_speed() -> u32 {
+    300
+}
+
+/// Default latin reading speed, in words per minute.
+fn default_latin_reading_speed() -> u32 {
+    200
+}
+
+fn is_cjk_char(c: char) -> bool {
+    matches!(c, '\u{4e00}'..='\u{9fff}' | '\u{3040}'..='\u{30ff}' | '\u{ac00}'..='\u{d7a3}')
+}
+
+/// Pagination context exposed to `season.jinja` when `paginate_by` is set.
+#[derive(Debug, Serialize)]
+struct Paginator {
+    current: usize,
+    total_pages: usize,
+    previous: Option<String>,
+    next: Option<String>,
+}
+
 /// The season entity config.
 /// It parsed from season directory's `zine.toml`.
 #[derive(Serialize, Deserialize)]
@@ -23,11 +63,47 @@ pub struct Season {
     pub intro: Option<String>,
     pub cover: Option<String>,
     pub path: String,
+    /// Author for this season's `atom.xml`/`rss.xml`. When unset, those feeds
+    /// carry no author; the site-wide feed author is not applied to them.
+    pub feed_author: Option<String>,
+    /// How many of the most recent articles this season's feed should include.
+    #[serde(default = "default_feed_item_count")]
+    pub feed_item_count: usize,
     #[serde(rename(deserialize = "article"))]
     #[serde(default)]
     pub articles: Vec<Article>,
+    /// When set, chunk the article listing into pages of this size instead of
+    /// rendering every article on a single `season.jinja` page.
+    pub paginate_by: Option<usize>,
+    /// CJK reading speed, in characters per minute, used for `reading_time`.
+    #[serde(default = "default_cjk_reading_speed")]
+    pub cjk_reading_speed: u32,
+    /// Latin reading speed, in words per minute, used for `reading_time`.
+    #[serde(default = "default_latin_reading_speed")]
+    pub latin_reading_speed: u32,
     #[serde(skip)]
     pub word_count: BTreeMap<String, u32>,
+    /// Sum of every article's `word_count`, for a season-level reading badge.
+    /// Only skipped on deserialize — `zine.toml` never sets this — since
+    /// `season.jinja` reads it back out of the `season` context key.
+    #[serde(skip_deserializing)]
+    pub total_word_count: u32,
+    /// Sum of every article's `reading_time`, in minutes. Serialized for the
+    /// same reason as `total_word_count`.
+    #[serde(skip_deserializing)]
+    pub total_reading_time: u32,
+    /// The inverted index backing this season's client-side full-text search.
+    #[serde(skip)]
+    pub search_index: SearchIndex,
+    /// This season's source directory, recorded during `parse` so `render` can
+    /// re-read the original `cover` image for responsive processing.
+    #[serde(skip)]
+    pub source_dir: PathBuf,
+    /// The resolved path of this season's intro file, recorded during `parse`
+    /// before `intro` is overwritten with its contents — watch mode needs the
+    /// path, since the intro file lives outside `source_dir`.
+    #[serde(skip)]
+    pub intro_path: Option<PathBuf>,
 }
 
 impl std::fmt::Debug for Season {
@@ -40,6 +116,11 @@ impl std::fmt::Debug for Season {
             .field("cover", &self.cover)
             .field("articles", &self.articles)
             .field("word_count", &self.word_count)
+            .field("total_word_count", &self.total_word_count)
+            .field("total_reading_time", &self.total_reading_time)
+            .field("search_index", &self.search_index)
+            .field("source_dir", &self.source_dir)
+            .field("intro_path", &self.intro_path)
             .finish()
     }
 }
@@ -65,13 +146,75 @@ impl Season {
             self.articles.get(current + 1),
         )
     }
+
+    fn page_url(&self, page: usize) -> String {
+        if page == 1 {
+            format!("{}/", self.slug)
+        } else {
+            format!("{}/page/{}/", self.slug, page)
+        }
+    }
+
+    /// Render the article listing, split across `season/page/2/`, `season/page/3/`, …
+    /// when `paginate_by` is set, or as a single page otherwise. Page 1 is always
+    /// rendered at the season root so existing links keep working.
+    ///
+    /// Pagination only takes effect if `season.jinja` lists the `articles`
+    /// context key set here, not `season.articles` — the latter always holds
+    /// every article regardless of page. `articles` equals `season.articles`
+    /// on the non-paginated path, so templates written against it work either way.
+    fn render_listing(&self, context: &Context, season_dir: &Path) -> Result<()> {
+        let Some(paginate_by) = self.paginate_by.filter(|&n| n > 0) else {
+            let mut context = context.clone();
+            context.insert("articles", &self.articles);
+            return Render::render("season.jinja", &context, season_dir.to_path_buf());
+        };
+
+        // `chunks` yields nothing for an empty slice, but page 1 must still be
+        // rendered (as the empty-listing page) to match the non-paginated path.
+        let mut pages: Vec<&[Article]> = self.articles.chunks(paginate_by).collect();
+        if pages.is_empty() {
+            pages.push(&[]);
+        }
+        let total_pages = pages.len();
+
+        for (index, page_articles) in pages.iter().enumerate() {
+            let current = index + 1;
+            let mut page_context = context.clone();
+            page_context.insert("articles", page_articles);
+            page_context.insert(
+                "paginator",
+                &Paginator {
+                    current,
+                    total_pages,
+                    previous: (current > 1).then(|| self.page_url(current - 1)),
+                    next: (current < total_pages).then(|| self.page_url(current + 1)),
+                },
+            );
+
+            let page_dest = if current == 1 {
+                season_dir.to_path_buf()
+            } else {
+                season_dir.join("page").join(current.to_string())
+            };
+            Render::render("season.jinja", &page_context, page_dest)?;
+        }
+        Ok(())
+    }
 }
 
 impl Entity for Season {
     fn parse(&mut self, source: &Path) -> Result<()> {
         // Parse intro file
         if let Some(intro_path) = &self.intro {
-            self.intro = Some(fs::read_to_string(&source.join(&intro_path))?);
+            // On a re-parse `intro` already holds the file's contents, so reuse
+            // the path recorded by the first parse.
+            let intro_path = self
+                .intro_path
+                .clone()
+                .unwrap_or_else(|| source.join(intro_path));
+            self.intro = Some(fs::read_to_string(&intro_path)?);
+            self.intro_path = Some(intro_path);
         }
 
         // Representing a zine.toml file for season.
@@ -90,21 +233,44 @@ impl Entity for Season {
             .sort_unstable_by_key(|article| article.pub_date);
 
         self.articles.parse(&dir)?;
+        self.source_dir = dir.clone();
 
-        // Analyze words frequencies
+        // Analyze word frequencies, build the search index, and derive each
+        // article's word count and reading time, all from the same jieba pass.
         let jieba = Jieba::new();
         let mut word_count: BTreeMap<String, u32> = BTreeMap::new();
-        for article in &self.articles {
+        let mut search_index = SearchIndexBuilder::default();
+        self.total_word_count = 0;
+        self.total_reading_time = 0;
+        for article in &mut self.articles {
+            search_index.begin_article(article.slug());
             let words = jieba.cut(&article.markdown, true);
+            let mut cjk_chars = 0u32;
+            let mut latin_words = 0u32;
             for word in words {
                 // Count word only if its length is greater than 1
                 if word.chars().count() > 1 {
                     let count = word_count.entry(word.to_string()).or_insert(0);
                     *count += 1;
+                    search_index.add_term(word);
+                }
+                if word.chars().any(is_cjk_char) {
+                    cjk_chars += word.chars().filter(|&c| is_cjk_char(c)).count() as u32;
+                } else if word.chars().any(char::is_alphanumeric) {
+                    latin_words += 1;
                 }
             }
+            search_index.end_article();
+
+            article.word_count = cjk_chars + latin_words;
+            article.reading_time = ((cjk_chars as f32 / self.cjk_reading_speed as f32)
+                + (latin_words as f32 / self.latin_reading_speed as f32))
+                .ceil() as u32;
+            self.total_word_count += article.word_count;
+            self.total_reading_time += article.reading_time;
         }
         self.word_count = word_count;
+        self.search_index = search_index.build();
 
         Ok(())
     }
@@ -118,9 +284,22 @@ impl Entity for Season {
             let mut context = context.clone();
             context.insert("siblings", &self.sibling_articles(index));
             context.insert("number", &(index + 1));
-            article.render(context.clone(), &season_dir.join(article.slug()))?;
+            context.insert("taxonomy_terms", &article.tags);
+            let article_dir = season_dir.join(article.slug());
+            article.render(context.clone(), &article_dir)?;
+            gemtext::render_article(&article.title, &article.markdown, &article_dir)?;
         }
 
+        gemtext::render_season_index(
+            &self.title,
+            &self
+                .articles
+                .iter()
+                .map(|article| (article.slug(), article.title.as_str()))
+                .collect::<Vec<_>>(),
+            &season_dir,
+        )?;
+
         context.insert(
             "meta",
             &Meta {
@@ -130,7 +309,48 @@ impl Entity for Season {
                 image: self.cover.as_deref().map(Cow::Borrowed),
             },
         );
-        Render::render("season.jinja", &context, season_dir)?;
+
+        // Generate responsive srcset variants of the cover, if any, alongside the
+        // bare path already carried on `meta.image`.
+        if let Some(cover) = &self.cover {
+            let mut pipeline = ImagePipeline::load(&self.source_dir)?;
+            let cover_image = pipeline.process(
+                &self.source_dir.join(cover),
+                cover,
+                &season_dir.join("images"),
+            )?;
+            pipeline.save()?;
+            context.insert("cover_image", &cover_image);
+        }
+
+        self.render_listing(&context, &season_dir)?;
+
+        // Emit the search index and its runtime next to the season output.
+        fs::write(
+            season_dir.join("search-index.json"),
+            serde_json::to_string(&self.search_index)?,
+        )?;
+        fs::write(season_dir.join("search.js"), SEARCH_RUNTIME_JS)?;
+
+        // Emit this season's own atom.xml/rss.xml. `link` already points at this
+        // season's root, so no season slug segment is inserted before article slugs.
+        // The combined site feed is built separately by `site::build_site_feed`.
+        let articles = self
+            .articles
+            .iter()
+            .map(|article| ("", article))
+            .collect::<Vec<_>>();
+        build_feed(
+            &FeedMeta {
+                title: &self.title,
+                author: self.feed_author.as_deref().unwrap_or_default(),
+                link: &self.slug,
+                item_count: self.feed_item_count,
+            },
+            &articles,
+            &season_dir,
+        )?;
+
         Ok(())
     }
 }
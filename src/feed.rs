@@ -0,0 +1,139 @@
+//! RSS and Atom feed generation.
+//!
+//! [`build_feed`] turns a title, link, and a slice of articles into both an
+//! `atom.xml` and an `rss.xml` document. [`Season::render`](crate::entity::Season::render)
+//! calls this to emit a per-season feed; [`crate::site::build_site_feed`] calls the
+//! same builder to assemble the combined site-wide feed from every season's articles.
+
+use std::path::Path;
+
+use anyhow::Result;
+use atom_syndication::{
+    Entry as AtomEntry, Feed as AtomFeed, FixedDateTime, Link as AtomLink, Person as AtomPerson,
+};
+use rss::{ChannelBuilder, Item as RssItem};
+
+use crate::{entity::article::Article, meta::extract_description_from_markdown};
+
+/// Feed-wide metadata, sourced from `zine.toml`.
+pub struct FeedMeta<'a> {
+    pub title: &'a str,
+    pub author: &'a str,
+    /// Link to the site or season root this feed represents; article links are
+    /// built by appending to it. A season's feed passes its slug here.
+    pub link: &'a str,
+    /// How many of the most recent articles to include.
+    pub item_count: usize,
+}
+
+/// Render `articles` as both `atom.xml` and `rss.xml` under `dest`.
+///
+/// Each article is paired with the path segment to insert between `meta.link`
+/// and the article's own slug — empty when `meta.link` already points at the
+/// article's season (the per-season feed), or the owning season's slug when
+/// `meta.link` points at the site root (the combined site feed).
+pub fn build_feed(meta: &FeedMeta, articles: &[(&str, &Article)], dest: &Path) -> Result<()> {
+    // Feeds want newest first. Each season's articles are sorted by `pub_date`
+    // ascending, but the site feed concatenates seasons, so sort across them too.
+    let mut recent = articles.iter().rev().collect::<Vec<_>>();
+    recent.sort_by_key(|(_, article)| std::cmp::Reverse(article.pub_date));
+    recent.truncate(meta.item_count);
+
+    write_atom(meta, &recent, dest)?;
+    write_rss(meta, &recent, dest)?;
+    Ok(())
+}
+
+fn article_link(meta: &FeedMeta, season_slug: &str, article_slug: &str) -> String {
+    let mut link = meta.link.trim_end_matches('/').to_owned();
+    if !season_slug.is_empty() {
+        link.push('/');
+        link.push_str(season_slug);
+    }
+    link.push('/');
+    link.push_str(article_slug);
+    link
+}
+
+fn write_atom(meta: &FeedMeta, articles: &[&(&str, &Article)], dest: &Path) -> Result<()> {
+    let entries = articles
+        .iter()
+        .map(|(season_slug, article)| {
+            let link = article_link(meta, season_slug, article.slug());
+            let published =
+                FixedDateTime::from(article.pub_date.and_hms_opt(0, 0, 0).unwrap_or_default());
+            let mut entry = AtomEntry::default();
+            entry.set_title(article.title.clone());
+            entry.set_links(vec![AtomLink {
+                href: link.clone(),
+                ..Default::default()
+            }]);
+            entry.set_id(link);
+            entry.set_summary(Some(
+                extract_description_from_markdown(&article.markdown).into(),
+            ));
+            entry.set_published(published);
+            entry.set_updated(published);
+            entry
+        })
+        .collect::<Vec<_>>();
+
+    let authors = if meta.author.is_empty() {
+        vec![]
+    } else {
+        vec![AtomPerson {
+            name: meta.author.to_owned(),
+            ..Default::default()
+        }]
+    };
+    // `articles` is newest-first (see `build_feed`), so the first entry's date
+    // is the feed's most recent update; an empty feed falls back to the epoch.
+    let updated = articles
+        .first()
+        .map(|(_, article)| {
+            FixedDateTime::from(article.pub_date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        })
+        .unwrap_or_default();
+    let feed = AtomFeed {
+        title: meta.title.into(),
+        authors,
+        entries,
+        updated,
+        ..Default::default()
+    };
+    std::fs::write(dest.join("atom.xml"), feed.to_string())?;
+    Ok(())
+}
+
+fn write_rss(meta: &FeedMeta, articles: &[&(&str, &Article)], dest: &Path) -> Result<()> {
+    let items = articles
+        .iter()
+        .map(|(season_slug, article)| {
+            let link = article_link(meta, season_slug, article.slug());
+            RssItem {
+                title: Some(article.title.clone()),
+                link: Some(link),
+                description: Some(extract_description_from_markdown(&article.markdown)),
+                pub_date: Some(
+                    article
+                        .pub_date
+                        .and_hms_opt(0, 0, 0)
+                        .unwrap_or_default()
+                        .and_utc()
+                        .to_rfc2822(),
+                ),
+                ..Default::default()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let managing_editor = (!meta.author.is_empty()).then(|| meta.author.to_owned());
+    let channel = ChannelBuilder::default()
+        .title(meta.title)
+        .link(meta.link)
+        .managing_editor(managing_editor)
+        .items(items)
+        .build();
+    std::fs::write(dest.join("rss.xml"), channel.to_string())?;
+    Ok(())
+}
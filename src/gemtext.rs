@@ -0,0 +1,89 @@
+//! Gemtext (`.gmi`) output, published alongside HTML so a zine can be served
+//! over the Gemini protocol.
+//!
+//! [`to_gemtext`] walks the same `markdown` already retained on each
+//! [`Article`](crate::entity::article::Article) and converts it with
+//! `pulldown_cmark`: headings become `#`/`##`/`###` lines, links become
+//! standalone `=>` lines, and paragraphs pass through as plain text.
+
+use std::{fs, path::Path};
+
+use anyhow::Result;
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag};
+
+/// Convert `markdown` to gemtext. Gemtext only has three heading levels, so
+/// anything deeper than `###` is clamped.
+pub fn to_gemtext(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut paragraph = String::new();
+    let mut link_url: Option<String> = None;
+    let mut link_text = String::new();
+    let mut in_link = false;
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::Heading(level, ..)) => {
+                flush_paragraph(&mut out, &mut paragraph);
+                let marks = match level {
+                    HeadingLevel::H1 => "#",
+                    HeadingLevel::H2 => "##",
+                    _ => "###",
+                };
+                paragraph.push_str(marks);
+                paragraph.push(' ');
+            }
+            Event::End(Tag::Heading(..)) => flush_paragraph(&mut out, &mut paragraph),
+            Event::Start(Tag::Link(_, url, _)) => {
+                in_link = true;
+                link_url = Some(url.to_string());
+                link_text.clear();
+            }
+            Event::End(Tag::Link(..)) => {
+                in_link = false;
+                if let Some(url) = link_url.take() {
+                    flush_paragraph(&mut out, &mut paragraph);
+                    out.push_str(&format!("=> {url} {link_text}\n\n"));
+                }
+            }
+            Event::End(Tag::Paragraph) => flush_paragraph(&mut out, &mut paragraph),
+            Event::Text(text) | Event::Code(text) => {
+                if in_link {
+                    link_text.push_str(&text);
+                } else {
+                    paragraph.push_str(&text);
+                }
+            }
+            Event::SoftBreak | Event::HardBreak => paragraph.push(' '),
+            _ => {}
+        }
+    }
+    flush_paragraph(&mut out, &mut paragraph);
+    out
+}
+
+fn flush_paragraph(out: &mut String, paragraph: &mut String) {
+    if !paragraph.is_empty() {
+        out.push_str(paragraph);
+        out.push_str("\n\n");
+        paragraph.clear();
+    }
+}
+
+/// Write a single article as `index.gmi` under `dest`.
+pub fn render_article(title: &str, markdown: &str, dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    let content = format!("# {title}\n\n{}", to_gemtext(markdown));
+    fs::write(dest.join("index.gmi"), content)?;
+    Ok(())
+}
+
+/// Write a season index listing each article as a `=>` link line.
+pub fn render_season_index(title: &str, articles: &[(&str, &str)], dest: &Path) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    let mut content = format!("# {title}\n\n");
+    for (slug, article_title) in articles {
+        content.push_str(&format!("=> ./{slug}/index.gmi {article_title}\n"));
+    }
+    fs::write(dest.join("index.gmi"), content)?;
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Responsive image processing for `cover` fields.
+//!
+//! Given a cover image path, [`ImagePipeline::process`] produces a handful of
+//! resized WebP variants, writes them under the output directory with
+//! content-hashed filenames, and returns a `srcset`-ready [`ResponsiveImage`]
+//! that also carries the original cover path, which is not copied. Work is cached by source
+//! mtime so repeated builds of an unchanged cover are a no-op.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use image::{imageops::FilterType, ImageFormat};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Responsive widths generated for every cover image, in pixels.
+const WIDTHS: [u32; 3] = [480, 960, 1440];
+
+/// One generated variant of a cover image.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageSource {
+    pub width: u32,
+    pub url: String,
+}
+
+/// A cover image plus its generated `srcset` variants, for use in place of
+/// the bare `cover: Option<String>` path in a template context.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResponsiveImage {
+    /// The original, unprocessed cover path, kept as a fallback `<img src>`.
+    pub original: String,
+    /// Resized WebP variants, narrowest first.
+    pub srcset: Vec<ImageSource>,
+}
+
+/// Source mtime + requested transform -> output filename, so unchanged covers
+/// are skipped on repeated builds.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ImageCache {
+    entries: BTreeMap<String, String>,
+}
+
+/// Resizes and converts cover images, caching work across builds.
+pub struct ImagePipeline {
+    cache_path: PathBuf,
+    cache: ImageCache,
+}
+
+impl ImagePipeline {
+    /// Load (or initialize) the cache file alongside the zine's content.
+    pub fn load(source_dir: &Path) -> Result<Self> {
+        let cache_path = source_dir.join(".zine-image-cache.json");
+        let cache = match fs::read_to_string(&cache_path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+            Err(_) => ImageCache::default(),
+        };
+        Ok(ImagePipeline { cache_path, cache })
+    }
+
+    /// Persist the cache back to disk. Call once after a build finishes.
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.cache_path, serde_json::to_string(&self.cache)?)?;
+        Ok(())
+    }
+
+    /// Process `cover`, writing resized WebP variants into `images_dir` and
+    /// returning a [`ResponsiveImage`] for the template context.
+    pub fn process(
+        &mut self,
+        source_path: &Path,
+        cover: &str,
+        images_dir: &Path,
+    ) -> Result<ResponsiveImage> {
+        let mtime = fs::metadata(source_path)?
+            .modified()?
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        fs::create_dir_all(images_dir)?;
+
+        let mut srcset = Vec::with_capacity(WIDTHS.len());
+        for width in WIDTHS {
+            let key = format!("{}:{}:webp", source_path.display(), mtime).to_string();
+            let key = format!("{width}:{key}");
+
+            // A cache hit only counts if the file it names is actually present in
+            // this build's output dir — a clean build wipes `images_dir` but keeps
+            // the cache, so a stale entry must be regenerated rather than trusted.
+            let cached = self
+                .cache
+                .entries
+                .get(&key)
+                .filter(|filename| images_dir.join(filename).is_file())
+                .cloned();
+
+            let filename = if let Some(filename) = cached {
+                filename
+            } else {
+                let filename = self.resize_to_webp(source_path, width, images_dir)?;
+                self.cache.entries.insert(key, filename.clone());
+                filename
+            };
+
+            // `filename` is only the basename written into `images_dir`; the
+            // template resolves `cover_image.srcset` relative to the season
+            // page itself, so the URL must include the `images/` subdir.
+            srcset.push(ImageSource {
+                width,
+                url: format!("images/{filename}"),
+            });
+        }
+
+        Ok(ResponsiveImage {
+            original: cover.to_owned(),
+            srcset,
+        })
+    }
+
+    fn resize_to_webp(&self, source_path: &Path, width: u32, images_dir: &Path) -> Result<String> {
+        let img = image::open(source_path)?;
+        let height = (img.height() as u64 * width as u64 / img.width() as u64).max(1) as u32;
+        let resized = img.resize(width, height, FilterType::Lanczos3);
+
+        let mut bytes = Vec::new();
+        resized.write_to(&mut std::io::Cursor::new(&mut bytes), ImageFormat::WebP)?;
+
+        let hash = Sha256::digest(&bytes);
+        let filename = format!("{:x}-{width}w.webp", hash);
+        fs::write(images_dir.join(&filename), &bytes)?;
+        Ok(filename)
+    }
+}
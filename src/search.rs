@@ -0,0 +1,103 @@
+//! Client-side full-text search index.
+//!
+//! Built from the same `jieba` tokenization pass that [`crate::entity::Season::parse`]
+//! already runs, so indexing costs nothing beyond recording the extra positions. The
+//! index is serialized to `search-index.json` and queried at runtime by the bundled
+//! `search.js` using BM25 ranking.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// BM25 term-frequency saturation parameter.
+const K1: f32 = 1.2;
+/// BM25 document-length normalization parameter.
+const B: f32 = 0.75;
+
+/// A term's occurrences within one article: how many, and the first offset.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Posting {
+    pub slug: String,
+    pub term_frequency: u32,
+    pub first_offset: u32,
+}
+
+/// The inverted index for a season's articles, serialized as `search-index.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// Tokenized term -> posting list, sorted by insertion order of articles.
+    pub postings: BTreeMap<String, Vec<Posting>>,
+    /// Article slug -> token count, i.e. BM25's `|d|`.
+    pub doc_lengths: BTreeMap<String, u32>,
+    /// Total number of indexed articles, i.e. BM25's `N`.
+    pub doc_count: u32,
+    /// Mean document length across all articles, i.e. BM25's `avgdl`.
+    pub avgdl: f32,
+    /// BM25 `k1` used when this index was built, exposed so the client doesn't
+    /// have to hardcode it.
+    pub k1: f32,
+    /// BM25 `b` used when this index was built.
+    pub b: f32,
+}
+
+/// Accumulates per-article token positions while a season's articles are parsed.
+#[derive(Debug, Default)]
+pub struct SearchIndexBuilder {
+    postings: BTreeMap<String, Vec<Posting>>,
+    doc_lengths: BTreeMap<String, u32>,
+    current_slug: String,
+    current_offset: u32,
+    current_terms: BTreeMap<String, (u32, u32)>,
+}
+
+impl SearchIndexBuilder {
+    /// Start accumulating terms for a new article.
+    pub fn begin_article(&mut self, slug: &str) {
+        self.current_slug = slug.to_owned();
+        self.current_offset = 0;
+        self.current_terms.clear();
+    }
+
+    /// Record one tokenized term at the current offset within the current article.
+    pub fn add_term(&mut self, term: &str) {
+        let entry = self
+            .current_terms
+            .entry(term.to_owned())
+            .or_insert((0, self.current_offset));
+        entry.0 += 1;
+        self.current_offset += 1;
+    }
+
+    /// Flush the terms accumulated for the current article into the index.
+    pub fn end_article(&mut self) {
+        self.doc_lengths
+            .insert(self.current_slug.clone(), self.current_offset);
+        for (term, (term_frequency, first_offset)) in std::mem::take(&mut self.current_terms) {
+            self.postings.entry(term).or_default().push(Posting {
+                slug: self.current_slug.clone(),
+                term_frequency,
+                first_offset,
+            });
+        }
+    }
+
+    /// Finish building the index, computing corpus-wide BM25 statistics.
+    pub fn build(self) -> SearchIndex {
+        let doc_count = self.doc_lengths.len() as u32;
+        let total_len: u32 = self.doc_lengths.values().sum();
+        let avgdl = if doc_count == 0 {
+            0.0
+        } else {
+            total_len as f32 / doc_count as f32
+        };
+
+        SearchIndex {
+            postings: self.postings,
+            doc_lengths: self.doc_lengths,
+            doc_count,
+            avgdl,
+            k1: K1,
+            b: B,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Site-wide aggregation across every season.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{
+    entity::{article::Article, season::Season},
+    feed::{build_feed, FeedMeta},
+    taxonomy::TaxonomyMap,
+};
+
+/// Flatten every season's articles into `(season_slug, article)` pairs, the
+/// shape both the site feed and the site taxonomy are built from.
+fn all_articles(seasons: &[Season]) -> Vec<(&str, &Article)> {
+    seasons
+        .iter()
+        .flat_map(|season| {
+            season
+                .articles
+                .iter()
+                .map(move |article| (season.slug.as_str(), article))
+        })
+        .collect()
+}
+
+/// Build the combined site feed (`atom.xml`/`rss.xml` at the site root) from
+/// every season's articles, alongside each season's own per-season feed.
+pub fn build_site_feed(seasons: &[Season], meta: &FeedMeta, dest: &Path) -> Result<()> {
+    build_feed(meta, &all_articles(seasons), dest)
+}
+
+/// Build the crate-wide taxonomy (`/tags/<slug>/` pages at the site root) from
+/// every season's articles, surfacing themes that cross season boundaries.
+pub fn build_site_taxonomy(seasons: &[Season], dest: &Path) -> Result<()> {
+    let articles = all_articles(seasons);
+    TaxonomyMap::build(&articles).render(&articles, dest)
+}
@@ -0,0 +1,71 @@
+//! Tags/categories declared on articles, aggregated crate-wide into listing pages.
+//!
+//! Each [`Article`] may declare `tags` in its `zine.toml` `[[article]]` entry.
+//! [`TaxonomyMap::build`] takes every season's articles together and collects
+//! `term -> article indices` across the whole site, so a single `/tags/<slug>/`
+//! page surfaces a term's articles regardless of which season they're in —
+//! the cross-season theme a season-only listing would otherwise hide.
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Result;
+use serde::Serialize;
+use slug::slugify;
+use tera::Context;
+
+use crate::{entity::article::Article, Render};
+
+/// One taxonomy term and the articles filed under it, each tagged with its
+/// owning season's slug so the listing can link back across seasons.
+#[derive(Debug, Serialize)]
+struct TaxonomyTerm<'a> {
+    term: &'a str,
+    slug: String,
+    articles: Vec<(&'a str, &'a Article)>,
+}
+
+/// `term -> article indices` across every season's articles.
+#[derive(Debug, Default, Serialize)]
+pub struct TaxonomyMap {
+    terms: BTreeMap<String, Vec<usize>>,
+}
+
+impl TaxonomyMap {
+    /// Aggregate taxonomy terms across `entries`, a flattened
+    /// `(season_slug, article)` list spanning every season.
+    pub fn build(entries: &[(&str, &Article)]) -> Self {
+        let mut terms: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (index, (_, article)) in entries.iter().enumerate() {
+            for tag in &article.tags {
+                terms.entry(tag.clone()).or_default().push(index);
+            }
+        }
+        TaxonomyMap { terms }
+    }
+
+    /// Render a `/tags/<slug>/` listing page per term, plus a taxonomy index page,
+    /// both rooted at `dest` (the site root). `entries` must be the same slice
+    /// passed to [`TaxonomyMap::build`].
+    pub fn render(&self, entries: &[(&str, &Article)], dest: &Path) -> Result<()> {
+        let tags_dir = dest.join("tags");
+        let mut index_terms = Vec::with_capacity(self.terms.len());
+
+        for (term, indices) in &self.terms {
+            let term_slug = slugify(term);
+            let entry = TaxonomyTerm {
+                term,
+                slug: term_slug.clone(),
+                articles: indices.iter().map(|&i| entries[i]).collect(),
+            };
+            let mut context = Context::new();
+            context.insert("taxonomy", &entry);
+            Render::render("taxonomy.jinja", &context, tags_dir.join(&term_slug))?;
+            index_terms.push(entry);
+        }
+
+        let mut context = Context::new();
+        context.insert("terms", &index_terms);
+        Render::render("taxonomy_index.jinja", &context, tags_dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+//! Incremental rebuilds for the dev workflow.
+//!
+//! [`watch`] parses every season up front — `source_dir`/`intro_path` are
+//! populated by `parse` and are otherwise empty on freshly-deserialized
+//! seasons, so matching a changed path against them only works once parsing
+//! has actually run at least once. From there, a changed path maps straight
+//! back to the season that owns it (by content directory or intro file), and
+//! only that season is re-parsed and re-rendered; a change under
+//! `templates_dir` falls back to a full rebuild, since `season.jinja` affects
+//! all of them.
+
+use std::{path::Path, sync::mpsc::channel, time::Duration};
+
+use anyhow::Result;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher as _};
+use tera::Context;
+
+use crate::entity::{season::Season, Entity};
+
+/// Watch `source` and `templates_dir` for changes, rebuilding affected seasons
+/// into `dest` until the process is interrupted.
+pub fn watch(
+    mut seasons: Vec<Season>,
+    source: &Path,
+    dest: &Path,
+    templates_dir: &Path,
+) -> Result<()> {
+    // Populate `source_dir`/`intro_path` and produce an initial build before
+    // watching for changes.
+    rebuild_all(&mut seasons, source, dest)?;
+
+    let (tx, rx) = channel();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(tx)?;
+    watcher.watch(source, RecursiveMode::Recursive)?;
+    watcher.watch(templates_dir, RecursiveMode::Recursive)?;
+
+    println!("Watching for changes, press Ctrl+C to stop...");
+    loop {
+        let event = match rx.recv_timeout(Duration::from_secs(1)) {
+            Ok(event) => event?,
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        };
+
+        for path in &event.paths {
+            if path.starts_with(templates_dir) {
+                rebuild_all(&mut seasons, source, dest)?;
+                break;
+            } else if let Some(season) = seasons.iter_mut().find(|season| {
+                path.starts_with(&season.source_dir) || season.intro_path.as_deref() == Some(path)
+            }) {
+                rebuild_one(season, source, dest)?;
+            }
+        }
+    }
+}
+
+fn rebuild_one(season: &mut Season, source: &Path, dest: &Path) -> Result<()> {
+    season.parse(source)?;
+    season.render(Context::new(), dest)?;
+    println!("Rebuilt season `{}`", season.slug);
+    Ok(())
+}
+
+fn rebuild_all(seasons: &mut [Season], source: &Path, dest: &Path) -> Result<()> {
+    for season in seasons.iter_mut() {
+        rebuild_one(season, source, dest)?;
+    }
+    println!("Rebuilt all seasons");
+    Ok(())
+}